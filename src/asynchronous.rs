@@ -5,23 +5,86 @@ use crate::{Error, OperationResult};
 use std::{future::Future, time::Duration};
 use tokio::time;
 
+/// Abstracts the notion of sleeping for a `Duration`, so the async retry loop is not hardcoded
+/// to `tokio::time::sleep`.
+///
+/// Implement this to run retries on another async runtime, or to substitute a mock/virtual-clock
+/// sleeper in tests, so they can assert on `total_delay` without actually waiting.
+pub trait Sleeper {
+    /// The future returned by `sleep`.
+    type Sleep: Future<Output = ()>;
+
+    /// Sleep for the given `Duration`.
+    fn sleep(&self, duration: Duration) -> Self::Sleep;
+}
+
+/// The default `Sleeper`, backed by `tokio::time::sleep`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokioSleeper;
+
+impl Sleeper for TokioSleeper {
+    type Sleep = time::Sleep;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        time::sleep(duration)
+    }
+}
+
+impl<S: Sleeper + ?Sized> Sleeper for &S {
+    type Sleep = S::Sleep;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        (**self).sleep(duration)
+    }
+}
+
 /// Retry the given asynchronous operation until it succeeds, or until the given `Duration`
 /// iterator ends.
-pub async fn retry<I, O, R, E, OR, F>(iterable: I, mut operation: O) -> Result<R, Error<E>>
+pub async fn retry<I, O, R, E, OR, F>(iterable: I, operation: O) -> Result<R, Error<E>>
 where
     I: IntoIterator<Item = Duration>,
     O: FnMut() -> F,
     OR: Into<OperationResult<R, E>>,
     F: Future<Output = OR>,
 {
-    retry_with_index(iterable, |_| operation()).await
+    retry_with_sleeper(iterable, TokioSleeper, operation).await
 }
 
 /// Retry the given asynchronous operation until it succeeds, or until the given `Duration`
 /// iterator ends, with each iteration of the operation receiving the number of the attempt as an
 /// argument.
-pub async fn retry_with_index<I, O, R, E, OR, F>(
+pub async fn retry_with_index<I, O, R, E, OR, F>(iterable: I, operation: O) -> Result<R, Error<E>>
+where
+    I: IntoIterator<Item = Duration>,
+    O: FnMut(u64) -> F,
+    OR: Into<OperationResult<R, E>>,
+    F: Future<Output = OR>,
+{
+    retry_with_index_and_sleeper(iterable, TokioSleeper, operation).await
+}
+
+/// Like `retry`, but sleeping between attempts through the given `Sleeper` instead of
+/// `tokio::time::sleep`, so callers can target another runtime or a deterministic test clock.
+pub async fn retry_with_sleeper<I, O, R, E, OR, F, S>(
+    iterable: I,
+    sleeper: S,
+    mut operation: O,
+) -> Result<R, Error<E>>
+where
+    I: IntoIterator<Item = Duration>,
+    O: FnMut() -> F,
+    OR: Into<OperationResult<R, E>>,
+    F: Future<Output = OR>,
+    S: Sleeper,
+{
+    retry_with_index_and_sleeper(iterable, sleeper, |_| operation()).await
+}
+
+/// Like `retry_with_index`, but sleeping between attempts through the given `Sleeper` instead of
+/// `tokio::time::sleep`, so callers can target another runtime or a deterministic test clock.
+pub async fn retry_with_index_and_sleeper<I, O, R, E, OR, F, S>(
     iterable: I,
+    sleeper: S,
     mut operation: O,
 ) -> Result<R, Error<E>>
 where
@@ -29,6 +92,7 @@ where
     O: FnMut(u64) -> F,
     OR: Into<OperationResult<R, E>>,
     F: Future<Output = OR>,
+    S: Sleeper,
 {
     let mut iterator = iterable.into_iter();
     let mut current_try = 1;
@@ -39,7 +103,7 @@ where
             OperationResult::Ok(value) => return Ok(value),
             OperationResult::Retry(error) => {
                 if let Some(delay) = iterator.next() {
-                    time::sleep(delay).await;
+                    sleeper.sleep(delay).await;
                     current_try += 1;
                     total_delay += delay;
                 } else {
@@ -67,12 +131,21 @@ where
 /// This is a workaround for cases when using `retry` is not possible because it is not possible
 /// to return a value capturing a reference from a closure [1].
 ///
+/// An optional middle argument selects the `Sleeper` to sleep with between attempts, the same
+/// way `retry_with_sleeper`/`retry_with_index_and_sleeper` do:
+/// `retry_future!(IntoIterator<Item = Duration>, Sleeper, Future<..>)`. Omitting it defaults to
+/// `TokioSleeper`.
+///
 /// [1] https://github.com/rustasync/team/issues/19
 #[macro_export]
 macro_rules! retry_future {
     ($delays:expr, $future:expr) => {
+        $crate::retry_future!($delays, $crate::asynchronous::TokioSleeper, $future)
+    };
+    ($delays:expr, $sleeper:expr, $future:expr) => {
         async {
             let mut iterator = $delays.into_iter();
+            let sleeper = $sleeper;
             let mut current_try = 1;
             let mut total_delay = ::std::time::Duration::default();
 
@@ -81,7 +154,7 @@ macro_rules! retry_future {
                     $crate::OperationResult::Ok(value) => return Ok(value),
                     $crate::OperationResult::Retry(error) => {
                         if let Some(delay) = iterator.next() {
-                            ::tokio::time::sleep(delay).await;
+                            $crate::asynchronous::Sleeper::sleep(&sleeper, delay).await;
                             current_try += 1;
                             total_delay += delay;
                         } else {
@@ -112,13 +185,31 @@ mod tests {
     use std::{sync::Arc, time::Duration};
     use tokio;
 
-    use super::{retry, retry_with_index};
+    use std::cell::Cell;
+
+    use super::{retry, retry_with_index, retry_with_index_and_sleeper, Sleeper};
     use crate::{
         delay::{Exponential, Fixed, NoDelay, Range},
         opresult::OperationResult,
         retry_future, Error,
     };
 
+    /// A `Sleeper` that does not actually wait, instead recording the total requested delay so
+    /// tests can assert on it without paying for real time.
+    #[derive(Default)]
+    struct MockSleeper {
+        elapsed: Cell<Duration>,
+    }
+
+    impl Sleeper for MockSleeper {
+        type Sleep = future::Ready<()>;
+
+        fn sleep(&self, duration: Duration) -> Self::Sleep {
+            self.elapsed.set(self.elapsed.get() + duration);
+            future::ready(())
+        }
+    }
+
     #[tokio::test]
     async fn succeeds_with_infinite_retries() {
         let mut collection = vec![1, 2, 3, 4, 5].into_iter();
@@ -304,4 +395,42 @@ mod tests {
 
         assert!(value < 100);
     }
+
+    #[tokio::test]
+    async fn succeeds_with_custom_sleeper_and_no_real_waiting() {
+        let mut collection = vec![1, 2, 3].into_iter();
+        let sleeper = MockSleeper::default();
+
+        let value = retry_with_index_and_sleeper(Fixed::from_millis(1_000), &sleeper, |_| {
+            match collection.next() {
+                Some(n) if n == 3 => future::ready(Ok(n)),
+                Some(_) => future::ready(Err("not 3")),
+                None => future::ready(Err("not 3")),
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(value, 3);
+        assert_eq!(sleeper.elapsed.get(), Duration::from_millis(2_000));
+    }
+
+    #[tokio::test]
+    async fn retry_future_macro_with_custom_sleeper() {
+        let mut collection = vec![1, 2].into_iter();
+        let sleeper = MockSleeper::default();
+
+        let value = retry_future!(Fixed::from_millis(1_000), &sleeper, async {
+            match collection.next() {
+                Some(n) if n == 2 => Ok(n),
+                Some(_) => Err("not 2"),
+                None => Err("not 2"),
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(value, 2);
+        assert_eq!(sleeper.elapsed.get(), Duration::from_millis(1_000));
+    }
 }
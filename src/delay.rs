@@ -214,6 +214,118 @@ impl From<RangeInclusive<Duration>> for Range {
     }
 }
 
+/// Each retry uses AWS's "decorrelated jitter" algorithm: a delay chosen uniformly between
+/// `base` and three times the previous delay, capped at `cap`.
+///
+/// This spreads retry load better than applying [`jitter`] to a pure `Exponential` series, since
+/// the series is derived from its own previous value rather than a fixed exponential curve.
+///
+/// See ["Exponential Backoff And Jitter"](https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/)
+/// for more details.
+#[derive(Debug)]
+pub struct DecorrelatedJitter {
+    base: u64,
+    cap: u64,
+    prev: u64,
+    rng: ThreadRng,
+}
+
+impl DecorrelatedJitter {
+    /// Create a new `DecorrelatedJitter` using the given base and cap, both in milliseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is greater than `cap`.
+    pub fn from_millis(base: u64, cap: u64) -> Self {
+        assert!(base <= cap, "base must be less than or equal to cap");
+
+        DecorrelatedJitter {
+            base,
+            cap,
+            prev: base,
+            rng: rng(),
+        }
+    }
+}
+
+impl Iterator for DecorrelatedJitter {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let upper = self.prev.checked_mul(3).unwrap_or(self.cap);
+        let sleep = Uniform::new_inclusive(self.base, upper)
+            .expect("Invalid Inputs")
+            .sample(&mut self.rng)
+            .min(self.cap);
+
+        self.prev = sleep;
+        Some(Duration::from_millis(sleep))
+    }
+}
+
+#[test]
+fn decorrelated_jitter_stays_within_bounds() {
+    let base = 10;
+    let cap = 100;
+    let mut iter = DecorrelatedJitter::from_millis(base, cap);
+
+    for _ in 0..1_000 {
+        let millis = iter.next().unwrap().as_millis() as u64;
+        assert!(millis >= base, "{millis} fell below base {base}");
+        assert!(millis <= cap, "{millis} exceeded cap {cap}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "base must be less than or equal to cap")]
+fn decorrelated_jitter_panics_when_base_exceeds_cap() {
+    DecorrelatedJitter::from_millis(100, 10);
+}
+
+/// A delay iterator adapter that clamps every yielded duration to a maximum, without limiting
+/// how many delays are produced.
+///
+/// See [`DelayExt::max_delay`] for a convenient way to construct one.
+#[derive(Debug)]
+pub struct MaxDelay<I> {
+    iterable: I,
+    max_delay: Duration,
+}
+
+impl<I> Iterator for MaxDelay<I>
+where
+    I: Iterator<Item = Duration>,
+{
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        self.iterable.next().map(|delay| delay.min(self.max_delay))
+    }
+}
+
+/// Extension trait adding `.max_delay()` to any delay iterator.
+pub trait DelayExt: Iterator<Item = Duration> + Sized {
+    /// Clamp every yielded duration to at most `max_delay`. Unlike `.take(n)`, this does not
+    /// limit the number of delays produced, only their size, which is what unbounded strategies
+    /// like `Exponential` need to behave as a "backoff capped at `max_delay`".
+    fn max_delay(self, max_delay: Duration) -> MaxDelay<Self> {
+        MaxDelay {
+            iterable: self,
+            max_delay,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Duration>> DelayExt for I {}
+
+#[test]
+fn max_delay_clamps_unbounded_growth() {
+    let mut iter = Exponential::from_millis(10).max_delay(Duration::from_millis(30));
+    assert_eq!(iter.next(), Some(Duration::from_millis(10)));
+    assert_eq!(iter.next(), Some(Duration::from_millis(30)));
+    assert_eq!(iter.next(), Some(Duration::from_millis(30)));
+}
+
 /// Apply full random jitter to a duration.
 pub fn jitter(duration: Duration) -> Duration {
     let jitter = random::<f64>();
@@ -221,3 +333,32 @@ pub fn jitter(duration: Duration) -> Duration {
     let nanos = ((f64::from(duration.subsec_nanos())) * jitter).ceil() as u32;
     Duration::new(secs, nanos)
 }
+
+/// Apply proportional jitter to a duration, keeping the result centered on `duration` rather
+/// than collapsing it toward zero the way [`jitter`] can.
+///
+/// `factor` is the maximum relative deviation and must be in `0.0..=1.0`. The result is
+/// `duration * (1.0 + r)` for `r` sampled uniformly from `[-factor, factor]`, computed over total
+/// nanoseconds with saturating conversion back to a `Duration`.
+///
+/// # Panics
+///
+/// Panics if `factor` is not in `0.0..=1.0`.
+pub fn jitter_with_factor(duration: Duration, factor: f64) -> Duration {
+    assert!(
+        (0.0..=1.0).contains(&factor),
+        "factor must be in 0.0..=1.0, got {factor}"
+    );
+
+    let ratio = Uniform::new_inclusive(-factor, factor)
+        .expect("Invalid Inputs")
+        .sample(&mut rng());
+    let nanos = (duration.as_nanos() as f64) * (1.0 + ratio);
+    Duration::from_nanos(nanos.max(0.0) as u64)
+}
+
+#[test]
+#[should_panic(expected = "factor must be in 0.0..=1.0")]
+fn jitter_with_factor_rejects_out_of_range_factor() {
+    jitter_with_factor(Duration::from_secs(30), 1.5);
+}
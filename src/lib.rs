@@ -0,0 +1,101 @@
+//! This crate provides utilities for retrying operations that can fail, with operation-specific
+//! stopping conditions and a variety of backoff strategies.
+//!
+//! By default, `retry` and `retry_with_index` run synchronously, sleeping between attempts with
+//! `std::thread::sleep`. Enable the `"asynchronous"` feature for `tokio`-based variants under
+//! [`asynchronous`].
+
+pub mod delay;
+mod opresult;
+
+#[cfg(feature = "asynchronous")]
+pub mod asynchronous;
+#[cfg(feature = "asynchronous")]
+pub mod policy;
+#[cfg(feature = "asynchronous")]
+pub mod stream;
+
+use std::{error, fmt, thread, time::Duration};
+
+pub use crate::opresult::OperationResult;
+
+/// Retry the given operation synchronously until it succeeds, or until the given `Duration`
+/// iterator ends.
+pub fn retry<I, O, R, E, OR>(iterable: I, mut operation: O) -> Result<R, Error<E>>
+where
+    I: IntoIterator<Item = Duration>,
+    O: FnMut() -> OR,
+    OR: Into<OperationResult<R, E>>,
+{
+    retry_with_index(iterable, |_| operation())
+}
+
+/// Retry the given operation synchronously until it succeeds, or until the given `Duration`
+/// iterator ends, with each iteration of the operation receiving the number of the attempt as an
+/// argument.
+pub fn retry_with_index<I, O, R, E, OR>(iterable: I, mut operation: O) -> Result<R, Error<E>>
+where
+    I: IntoIterator<Item = Duration>,
+    O: FnMut(u64) -> OR,
+    OR: Into<OperationResult<R, E>>,
+{
+    let mut iterator = iterable.into_iter();
+    let mut current_try = 1;
+    let mut total_delay = Duration::default();
+
+    loop {
+        match operation(current_try).into() {
+            OperationResult::Ok(value) => return Ok(value),
+            OperationResult::Retry(error) => {
+                if let Some(delay) = iterator.next() {
+                    thread::sleep(delay);
+                    current_try += 1;
+                    total_delay += delay;
+                } else {
+                    return Err(Error {
+                        error,
+                        total_delay,
+                        tries: current_try,
+                    });
+                }
+            }
+            OperationResult::Err(error) => {
+                return Err(Error {
+                    error,
+                    total_delay,
+                    tries: current_try,
+                });
+            }
+        }
+    }
+}
+
+/// The final, unretryable result of calling `retry`/`retry_with_index`, with some metadata about
+/// how the operation got there.
+#[derive(Debug, PartialEq)]
+pub struct Error<E> {
+    /// The error that ended the retry loop: the one returned by the operation on its last
+    /// attempt, except that `policy::RetryPolicy::with_first_error` returns the first retryable
+    /// error here instead when the retries run out.
+    pub error: E,
+    /// The total amount of time spent sleeping between attempts.
+    pub total_delay: Duration,
+    /// The number of attempts that were made.
+    pub tries: u64,
+}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Gave up after {} attempts, {:?} total delay: {}",
+            self.tries, self.total_delay, self.error
+        )
+    }
+}
+
+impl<E: error::Error> error::Error for Error<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -0,0 +1,18 @@
+/// The result of a single attempt of an operation passed to `retry`/`retry_with_index`.
+pub enum OperationResult<R, E> {
+    /// The operation completed successfully.
+    Ok(R),
+    /// The operation failed, but should be retried according to the given `Duration` iterator.
+    Retry(E),
+    /// The operation failed in a way that should not be retried.
+    Err(E),
+}
+
+impl<R, E> From<Result<R, E>> for OperationResult<R, E> {
+    fn from(r: Result<R, E>) -> Self {
+        match r {
+            Ok(r) => OperationResult::Ok(r),
+            Err(e) => OperationResult::Retry(e),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! A builder-style, declarative alternative to calling `asynchronous::retry` directly. This
+//! module is enabled with the `"asynchronous"` feature.
+
+use crate::{Error, OperationResult};
+use std::{future::Future, time::Duration};
+use tokio::time;
+
+/// A builder for configuring and running an asynchronous retry loop.
+///
+/// `RetryPolicy` bundles a delay iterator with an optional maximum number of attempts, an
+/// optional maximum total delay budget, and an optional predicate deciding whether a given error
+/// is retryable at all. This avoids hand-mapping every fallible call site's result into an
+/// `OperationResult` just to encode those same limits.
+pub struct RetryPolicy<I, E> {
+    delays: I,
+    max_retries: Option<u64>,
+    max_delay: Option<Duration>,
+    retryable: Option<Box<dyn FnMut(&E) -> bool + Send>>,
+    first_error: bool,
+}
+
+impl<I, E> RetryPolicy<I, E>
+where
+    I: Iterator<Item = Duration>,
+{
+    /// Create a new `RetryPolicy` driven by the given delay iterator.
+    pub fn new(delays: I) -> Self {
+        RetryPolicy {
+            delays,
+            max_retries: None,
+            max_delay: None,
+            retryable: None,
+            first_error: false,
+        }
+    }
+
+    /// Cap the number of attempts made, including the first.
+    pub fn with_max_retries(mut self, max_retries: u64) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Stop retrying once the accumulated delay would exceed `max_delay`.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = Some(max_delay);
+        self
+    }
+
+    /// Only retry errors for which `predicate` returns `true`; every other error becomes fatal
+    /// immediately, regardless of how many attempts or how much delay budget remain.
+    pub fn with_retry_if<P>(mut self, predicate: P) -> Self
+    where
+        P: FnMut(&E) -> bool + Send + 'static,
+    {
+        self.retryable = Some(Box::new(predicate));
+        self
+    }
+
+    /// On exhaustion, return the *first* retryable error encountered instead of the last.
+    ///
+    /// The initial failure is often the most diagnostic one (e.g. the original connection
+    /// refusal rather than a later "already in progress"), so this mirrors
+    /// `fuchsia_backoff`'s `retry_or_first_error` behavior. Has no effect on a fatal error
+    /// returned directly from `operation` or rejected by `with_retry_if`, since those are
+    /// already the error that ended the loop.
+    pub fn with_first_error(mut self) -> Self {
+        self.first_error = true;
+        self
+    }
+
+    /// Run `operation` until it succeeds, the predicate rejects an error, or the policy's limits
+    /// are reached.
+    pub async fn retry<O, R, OR, F>(self, mut operation: O) -> Result<R, Error<E>>
+    where
+        O: FnMut() -> F,
+        OR: Into<OperationResult<R, E>>,
+        F: Future<Output = OR>,
+    {
+        self.retry_with_index(|_| operation()).await
+    }
+
+    /// Like `retry`, but `operation` also receives the number of the current attempt.
+    pub async fn retry_with_index<O, R, OR, F>(mut self, mut operation: O) -> Result<R, Error<E>>
+    where
+        O: FnMut(u64) -> F,
+        OR: Into<OperationResult<R, E>>,
+        F: Future<Output = OR>,
+    {
+        let mut current_try = 1;
+        let mut total_delay = Duration::default();
+        let mut first_error: Option<E> = None;
+
+        loop {
+            match operation(current_try).await.into() {
+                OperationResult::Ok(value) => return Ok(value),
+                OperationResult::Err(error) => {
+                    return Err(Error {
+                        error,
+                        total_delay,
+                        tries: current_try,
+                    });
+                }
+                OperationResult::Retry(error) => {
+                    if let Some(retryable) = self.retryable.as_mut() {
+                        if !retryable(&error) {
+                            return Err(Error {
+                                error,
+                                total_delay,
+                                tries: current_try,
+                            });
+                        }
+                    }
+
+                    let exhausted = self
+                        .max_retries
+                        .is_some_and(|max_retries| current_try >= max_retries);
+
+                    let delay = if exhausted { None } else { self.delays.next() };
+
+                    match delay {
+                        Some(delay)
+                            if self
+                                .max_delay
+                                .is_none_or(|max_delay| total_delay + delay <= max_delay) =>
+                        {
+                            if self.first_error && first_error.is_none() {
+                                first_error = Some(error);
+                            }
+
+                            time::sleep(delay).await;
+                            current_try += 1;
+                            total_delay += delay;
+                        }
+                        _ => {
+                            let error = if self.first_error {
+                                first_error.take().unwrap_or(error)
+                            } else {
+                                error
+                            };
+
+                            return Err(Error {
+                                error,
+                                total_delay,
+                                tries: current_try,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::future;
+
+    use super::RetryPolicy;
+    use crate::{delay::Fixed, Error};
+
+    #[tokio::test]
+    async fn exhaustion_returns_last_error_by_default() {
+        let mut attempt = 0;
+
+        let res = RetryPolicy::new(Fixed::from_millis(1).take(2))
+            .retry(|| {
+                attempt += 1;
+                future::ready(Err::<(), _>(attempt))
+            })
+            .await;
+
+        assert_eq!(
+            res,
+            Err(Error {
+                error: 3,
+                tries: 3,
+                total_delay: std::time::Duration::from_millis(2),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn exhaustion_returns_first_error_when_requested() {
+        let mut attempt = 0;
+
+        let res = RetryPolicy::new(Fixed::from_millis(1).take(2))
+            .with_first_error()
+            .retry(|| {
+                attempt += 1;
+                future::ready(Err::<(), _>(attempt))
+            })
+            .await;
+
+        assert_eq!(
+            res,
+            Err(Error {
+                error: 1,
+                tries: 3,
+                total_delay: std::time::Duration::from_millis(2),
+            })
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! A `Stream`-based view over a retry loop, yielding one item per attempt. This module is
+//! enabled with the `"asynchronous"` feature.
+
+use crate::OperationResult;
+use futures::Stream;
+use pin_project_lite::pin_project;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{self, Sleep};
+
+pin_project! {
+    #[project = StateProj]
+    enum State<F> {
+        Initial,
+        Waiting { #[pin] sleep: Sleep },
+        Running { #[pin] future: F },
+    }
+}
+
+pin_project! {
+    /// A `Stream` that drives `operation` once immediately and then once more after each delay in
+    /// `delays`, yielding the result of every attempt and sleeping between retryable failures.
+    ///
+    /// Unlike [`crate::asynchronous::retry_with_index`], which only surfaces the final outcome,
+    /// this lets callers observe (and log or measure) every failed attempt as it happens, and
+    /// compose retries into larger stream pipelines.
+    ///
+    /// Construct one with [`retry_stream`].
+    pub struct RetryStream<I, O, F> {
+        delays: I,
+        operation: O,
+        current_try: u64,
+        #[pin]
+        state: State<F>,
+        done: bool,
+    }
+}
+
+/// Turn a delay iterator and an operation factory into a `Stream<Item = Result<R, E>>`.
+///
+/// The stream yields one item per attempt (`Ok` on success, `Err` on every retryable or fatal
+/// failure) and ends after the operation succeeds, a fatal error occurs, or `delays` runs out.
+pub fn retry_stream<I, O, R, E, OR, F>(delays: I, operation: O) -> RetryStream<I, O, F>
+where
+    I: Iterator<Item = Duration>,
+    O: FnMut(u64) -> F,
+    OR: Into<OperationResult<R, E>>,
+    F: Future<Output = OR>,
+{
+    RetryStream {
+        delays,
+        operation,
+        current_try: 1,
+        state: State::Initial,
+        done: false,
+    }
+}
+
+impl<I, O, R, E, OR, F> Stream for RetryStream<I, O, F>
+where
+    I: Iterator<Item = Duration>,
+    O: FnMut(u64) -> F,
+    OR: Into<OperationResult<R, E>>,
+    F: Future<Output = OR>,
+{
+    type Item = Result<R, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Initial => {
+                    let future = (this.operation)(*this.current_try);
+                    this.state.set(State::Running { future });
+                }
+                StateProj::Waiting { sleep } => {
+                    if sleep.poll(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+                    let future = (this.operation)(*this.current_try);
+                    this.state.set(State::Running { future });
+                }
+                StateProj::Running { future } => {
+                    let result = match future.poll(cx) {
+                        Poll::Ready(result) => result,
+                        Poll::Pending => return Poll::Pending,
+                    };
+
+                    return match result.into() {
+                        OperationResult::Ok(value) => {
+                            *this.done = true;
+                            Poll::Ready(Some(Ok(value)))
+                        }
+                        OperationResult::Err(error) => {
+                            *this.done = true;
+                            Poll::Ready(Some(Err(error)))
+                        }
+                        OperationResult::Retry(error) => {
+                            if let Some(delay) = this.delays.next() {
+                                *this.current_try += 1;
+                                this.state.set(State::Waiting {
+                                    sleep: time::sleep(delay),
+                                });
+                            } else {
+                                *this.done = true;
+                            }
+                            Poll::Ready(Some(Err(error)))
+                        }
+                    };
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::retry_stream;
+    use crate::delay::NoDelay;
+    use futures::{future, StreamExt};
+
+    #[tokio::test]
+    async fn yields_one_item_per_attempt() {
+        let mut collection = vec![1, 2, 3].into_iter();
+
+        let results: Vec<Result<u8, &str>> = retry_stream(NoDelay, |_| {
+            future::ready(match collection.next() {
+                Some(n) if n == 3 => Ok(n),
+                Some(_) => Err("not 3"),
+                None => Err("not 3"),
+            })
+        })
+        .collect()
+        .await;
+
+        assert_eq!(results, vec![Err("not 3"), Err("not 3"), Ok(3)]);
+    }
+}